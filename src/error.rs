@@ -0,0 +1,29 @@
+use wasm_bindgen::JsValue;
+
+/// The error type used throughout this crate.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An opaque JS value (such as a rejection from the backend, or a message describing a
+    /// failure on the frontend side) which couldn't be downcast into anything more useful.
+    #[error("{0:?}")]
+    Other(JsValue),
+    /// Failed to (de)serialize a value crossing the JS <-> Rust boundary.
+    #[error(transparent)]
+    Serde(#[from] serde_wasm_bindgen::Error),
+}
+
+/// The result type used throughout this crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The error returned by [`crate::tauri::invoke_result`], letting callers match on the backend
+/// command's own `E` when it could be recovered, instead of only ever seeing an opaque rejection.
+#[derive(Debug, thiserror::Error)]
+pub enum InvokeError<E: std::fmt::Debug> {
+    /// The rejection was successfully deserialized into the backend command's error type.
+    #[error("{0:?}")]
+    Typed(E),
+    /// The rejection couldn't be deserialized into `E`, so its raw form is returned instead (as
+    /// [`Error::Other`]), or the successful response couldn't be deserialized (as [`Error::Serde`]).
+    #[error(transparent)]
+    Other(#[from] Error),
+}
@@ -0,0 +1,326 @@
+//! Access the HTTP client written in Rust.
+//!
+//! This package is also accessible with `window.__TAURI__.http` when [`build.withGlobalTauri`](https://tauri.app/v1/api/config/#buildconfig.withglobaltauri) in `tauri.conf.json` is set to `true`.
+//!
+//! The APIs must be added to [`tauri.allowlist.http`](https://tauri.app/v1/api/config/#allowlistconfig.http) in `tauri.conf.json`:
+//! ```json
+//! {
+//!     "tauri": {
+//!         "allowlist": {
+//!             "http": {
+//!                 "all": true, // enable all http APIs
+//!                 "request": true // enable HTTP request API
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::tauri::invoke;
+
+/// The HTTP verb to use when making a [`Request`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HttpVerb {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch,
+    Head,
+    Options,
+}
+
+/// The body of a [`Request`].
+///
+/// `Auto` inspects the given JSON value and picks the most sensible body encoding,
+/// mirroring what the JS `Body.*` helpers do.
+///
+/// Serialized with the variant name as-is (`"Json"`, `"Form"`, `"Bytes"`, `"Auto"`), matching
+/// `tauri-plugin-http`'s wire format — unlike [`HttpVerb`], these variant names aren't re-cased
+/// to match an HTTP-spec token, so there's no `rename_all` here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum Body {
+    /// A JSON body, serialized with `Content-Type: application/json`.
+    Json(serde_json::Value),
+    /// A form body, serialized with `Content-Type: application/x-www-form-urlencoded`.
+    Form(HashMap<String, String>),
+    /// A raw byte body.
+    Bytes(Vec<u8>),
+    /// Infer the most appropriate body representation from a JSON value.
+    Auto(serde_json::Value),
+}
+
+/// An HTTP request, to be sent with [`Client::send`] or [`fetch`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    method: HttpVerb,
+    url: Url,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    headers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    query: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<Body>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connect_timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    read_timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    follow_redirects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_redirections: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_compression: Option<bool>,
+}
+
+impl Request {
+    /// Create a new request for the given `method` and `url`.
+    pub fn new(method: HttpVerb, url: Url) -> Self {
+        Self {
+            method,
+            url,
+            headers: HashMap::new(),
+            query: HashMap::new(),
+            body: None,
+            timeout: None,
+            connect_timeout: None,
+            read_timeout: None,
+            follow_redirects: None,
+            max_redirections: None,
+            allow_compression: None,
+        }
+    }
+
+    /// Append a header to this request.
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Append a query parameter to this request.
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the body of this request.
+    pub fn body(mut self, body: Body) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Set the total timeout for this request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Set the timeout for establishing the connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Set the timeout for reading the response.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Whether to follow redirects or not. Defaults to `true`.
+    pub fn follow_redirects(mut self, follow: bool) -> Self {
+        self.follow_redirects = Some(follow);
+        self
+    }
+
+    /// The maximum number of redirections to follow. Defaults to unlimited.
+    pub fn max_redirections(mut self, max: usize) -> Self {
+        self.max_redirections = Some(max);
+        self
+    }
+
+    /// Whether to allow the server to respond with a compressed body or not. Defaults to `true`.
+    pub fn allow_compression(mut self, allow: bool) -> Self {
+        self.allow_compression = Some(allow);
+        self
+    }
+}
+
+/// The expected shape of a [`Response`]'s body.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResponseType {
+    Json,
+    Text,
+    Binary,
+}
+
+/// The raw response received from [`Client::send`] before its body has been decoded.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawResponse {
+    url: Url,
+    status: u16,
+    headers: HashMap<String, String>,
+    data: serde_json::Value,
+}
+
+/// A response received from the backend HTTP client.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// The final URL of the response, after following any redirects.
+    pub url: Url,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The headers of the response.
+    pub headers: HashMap<String, String>,
+    /// The decoded body of the response.
+    pub data: T,
+}
+
+/// A client used to send HTTP requests through the Rust backend, bypassing CORS restrictions
+/// that apply to `fetch` calls made directly from the webview.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::http::{Client, Request, HttpVerb, ResponseType};
+/// use url::Url;
+///
+/// let client = Client::new().await?;
+/// let request = Request::new(HttpVerb::Get, Url::parse("https://example.com")?);
+/// let response = client.send::<String>(request, ResponseType::Text).await?;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Client(u32);
+
+impl Client {
+    /// Create a new HTTP client.
+    pub async fn new() -> crate::Result<Self> {
+        let id: u32 = invoke("plugin:http|create_client", &()).await?;
+
+        Ok(Self(id))
+    }
+
+    /// Send a request through this client, decoding the response as `response_type`.
+    pub async fn send<T: DeserializeOwned>(
+        &self,
+        request: Request,
+        response_type: ResponseType,
+    ) -> crate::Result<Response<T>> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args {
+            client: u32,
+            request: Request,
+            response_type: ResponseType,
+        }
+
+        let raw: RawResponse = invoke(
+            "plugin:http|fetch",
+            &Args {
+                client: self.0,
+                request,
+                response_type,
+            },
+        )
+        .await?;
+
+        let data = serde_json::from_value(raw.data).map_err(|_| {
+            crate::Error::Other(wasm_bindgen::JsValue::from_str(
+                "failed to decode response body",
+            ))
+        })?;
+
+        Ok(Response {
+            url: raw.url,
+            status: raw.status,
+            headers: raw.headers,
+            data,
+        })
+    }
+}
+
+/// Make an HTTP request using a one-off [`Client`], decoding the response as `response_type`.
+///
+/// This is a convenience wrapper around [`Client::new`] followed by [`Client::send`] for
+/// callers that don't need to reuse the client across multiple requests.
+pub async fn fetch<T: DeserializeOwned>(
+    request: Request,
+    response_type: ResponseType,
+) -> crate::Result<Response<T>> {
+    Client::new().await?.send(request, response_type).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_verb_serializes_as_uppercase_method_names() {
+        assert_eq!(
+            serde_json::to_value(HttpVerb::Get).unwrap(),
+            serde_json::json!("GET")
+        );
+        assert_eq!(
+            serde_json::to_value(HttpVerb::Post).unwrap(),
+            serde_json::json!("POST")
+        );
+        assert_eq!(
+            serde_json::to_value(HttpVerb::Head).unwrap(),
+            serde_json::json!("HEAD")
+        );
+        assert_eq!(
+            serde_json::to_value(HttpVerb::Options).unwrap(),
+            serde_json::json!("OPTIONS")
+        );
+    }
+
+    #[test]
+    fn body_serializes_with_untouched_variant_names() {
+        assert_eq!(
+            serde_json::to_value(Body::Json(serde_json::json!({"a": 1}))).unwrap(),
+            serde_json::json!({"type": "Json", "payload": {"a": 1}})
+        );
+        assert_eq!(
+            serde_json::to_value(Body::Form(HashMap::from([(
+                "a".to_string(),
+                "b".to_string()
+            )])))
+            .unwrap(),
+            serde_json::json!({"type": "Form", "payload": {"a": "b"}})
+        );
+        assert_eq!(
+            serde_json::to_value(Body::Bytes(vec![1, 2, 3])).unwrap(),
+            serde_json::json!({"type": "Bytes", "payload": [1, 2, 3]})
+        );
+        assert_eq!(
+            serde_json::to_value(Body::Auto(serde_json::json!("hi"))).unwrap(),
+            serde_json::json!({"type": "Auto", "payload": "hi"})
+        );
+    }
+
+    #[test]
+    fn request_skips_unset_optional_fields() {
+        let request = Request::new(HttpVerb::Get, Url::parse("https://example.com").unwrap());
+        let value = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({"method": "GET", "url": "https://example.com/"})
+        );
+    }
+}
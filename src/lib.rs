@@ -0,0 +1,6 @@
+pub mod error;
+
+pub mod http;
+pub mod tauri;
+
+pub use error::{Error, Result};
@@ -1,3 +1,10 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use futures::Stream;
 use serde::{de::DeserializeOwned, Serialize};
 use url::Url;
 
@@ -46,6 +53,170 @@ pub async fn convert_file_src(file_path: &str, protocol: Option<&str>) -> Url {
     .unwrap()
 }
 
+/// Convert a device file path to a URL for use with seekable media (video/audio).
+///
+/// This is otherwise identical to [`convert_file_src`] — Tauri's asset protocol already answers
+/// `Range` requests against any `asset:`/`https://asset.localhost` URL with `206 Content-Range`
+/// responses, so no extra setup is needed for seeking to work and memory to stay bounded for
+/// multi-gigabyte files. `mime` isn't used by this function; [`convert_streaming_file_src_into`]
+/// is the variant that sets the `<source>` element's `type` attribute from its own `mime`.
+/// See [`convert_file_src`] for the required `tauri.conf.json` setup (`security.csp` and
+/// `allowlist.protocol`).
+///
+/// @param  filePath The file path.
+/// @param  protocol The protocol to use. Defaults to `asset`. You only need to set this when using a custom protocol.
+/// @param  mime The MIME type of the file, e.g. `video/mp4`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::tauri::convert_streaming_file_src;
+///
+/// let asset_url = convert_streaming_file_src("assets/movie.mp4", None, "video/mp4").await;
+/// ```
+///
+/// @return the URL that can be used as source on the webview.
+#[inline(always)]
+pub async fn convert_streaming_file_src(
+    file_path: &str,
+    protocol: Option<&str>,
+    mime: &str,
+) -> Url {
+    let _ = mime;
+    convert_file_src(file_path, protocol).await
+}
+
+/// Append a `<source>` element carrying a [`convert_streaming_file_src`] URL to `element`, so the
+/// caller doesn't have to manage range headers or the `type` attribute itself.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::tauri::convert_streaming_file_src_into;
+///
+/// let window = web_sys::window().expect("no global `window` exists");
+/// let document = window.document().expect("should have a document on window");
+/// let video = document.get_element_by_id("my-video").unwrap();
+///
+/// convert_streaming_file_src_into(&video, "assets/movie.mp4", None, "video/mp4").await?;
+/// ```
+///
+/// @param element The `<video>`/`<audio>` element to append the `<source>` to.
+/// @param filePath The file path.
+/// @param protocol The protocol to use. Defaults to `asset`. You only need to set this when using a custom protocol.
+/// @param mime The MIME type of the file, e.g. `video/mp4`, set as the `<source>`'s `type` attribute.
+pub async fn convert_streaming_file_src_into(
+    element: &web_sys::Element,
+    file_path: &str,
+    protocol: Option<&str>,
+    mime: &str,
+) -> Result<(), wasm_bindgen::JsValue> {
+    let url = convert_streaming_file_src(file_path, protocol, mime).await;
+
+    let document = element
+        .owner_document()
+        .expect("element has no owner document");
+    let source = document.create_element("source")?;
+
+    source.set_attribute("type", mime)?;
+    source.set_attribute("src", url.as_str())?;
+
+    element.append_child(&source)?;
+
+    Ok(())
+}
+
+/// Ask the backend to extend the asset protocol's own scope at runtime to allow access to a
+/// single file, without having to pre-declare it under `assetScope` in `tauri.conf.json`.
+///
+/// `"plugin:asset|allow_file"` is not a stock Tauri IPC command — Tauri doesn't expose scope
+/// mutation over IPC by default, precisely because scope is meant to only be widened by trusted
+/// Rust code, not webview content. This function assumes the application registers a command
+/// under that name itself; it's a thin `invoke` wrapper, not a guarantee that such a command
+/// exists.
+///
+/// # Security
+///
+/// Letting webview JS unlock arbitrary new paths at runtime inverts Tauri's scope model, where
+/// scope is normally only widened by trusted Rust code so that untrusted frontend content can't
+/// unlock paths itself. If you implement the backend command this calls, constrain what it's
+/// willing to allow yourself (e.g. only paths under `app_data_dir`, never an attacker-controlled
+/// absolute path) — do not simply forward `path` to
+/// [`Scope::allow_file`](https://docs.rs/tauri/latest/tauri/scope/struct.Scope.html#method.allow_file)
+/// unchecked.
+///
+/// Note this also targets the asset protocol's own [`Scope`], which is a separate instance from
+/// the one managing the filesystem plugin's general read/write access — allowing a path here does
+/// not also allow it for fs plugin commands, and vice versa.
+///
+/// [`Scope`]: https://docs.rs/tauri/latest/tauri/scope/struct.Scope.html
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::path::{app_data_dir, join};
+/// use tauri_api::tauri::{allow_asset_file, convert_file_src};
+///
+/// let app_data_dir_path = app_data_dir().await;
+/// let file_path = join(app_data_dir_path, "assets/video.mp4").await;
+///
+/// allow_asset_file(&file_path).await?;
+/// let asset_url = convert_file_src(&file_path, None).await;
+/// ```
+///
+/// @param path The path to the file to allow.
+#[inline(always)]
+pub async fn allow_asset_file(path: &str) -> crate::Result<()> {
+    invoke(
+        "plugin:asset|allow_file",
+        &serde_json::json!({ "path": path }),
+    )
+    .await
+}
+
+/// Extend the asset protocol's own scope at runtime to allow access to a directory, optionally
+/// including every file and subdirectory underneath it. See [`allow_asset_file`] for the
+/// `# Security` considerations that apply here too, and how this scope relates to the filesystem
+/// plugin's scope.
+///
+/// @param path The path to the directory to allow.
+/// @param recursive Whether to also allow every file and subdirectory underneath `path`.
+#[inline(always)]
+pub async fn allow_asset_directory(path: &str, recursive: bool) -> crate::Result<()> {
+    invoke(
+        "plugin:asset|allow_directory",
+        &serde_json::json!({ "path": path, "recursive": recursive }),
+    )
+    .await
+}
+
+/// Remove a single file previously allowed with [`allow_asset_file`] from the asset protocol's
+/// scope.
+///
+/// @param path The path to the file to forbid.
+#[inline(always)]
+pub async fn forbid_asset_file(path: &str) -> crate::Result<()> {
+    invoke(
+        "plugin:asset|forbid_file",
+        &serde_json::json!({ "path": path }),
+    )
+    .await
+}
+
+/// Remove a directory previously allowed with [`allow_asset_directory`] from the asset protocol's
+/// scope, optionally including every file and subdirectory underneath it.
+///
+/// @param path The path to the directory to forbid.
+/// @param recursive Whether to also forbid every file and subdirectory underneath `path`.
+#[inline(always)]
+pub async fn forbid_asset_directory(path: &str, recursive: bool) -> crate::Result<()> {
+    invoke(
+        "plugin:asset|forbid_directory",
+        &serde_json::json!({ "path": path, "recursive": recursive }),
+    )
+    .await
+}
+
 /// Sends a message to the backend.
 ///
 /// # Example
@@ -72,6 +243,58 @@ pub async fn invoke<A: Serialize, R: DeserializeOwned>(cmd: &str, args: &A) -> c
     serde_wasm_bindgen::from_value(raw).map_err(Into::into)
 }
 
+/// Sends a message to the backend, recovering the backend command's own error type from a
+/// rejection instead of collapsing it into an opaque [`crate::Error::Other`].
+///
+/// This is useful when the backend command returns a `Result<_, E>` and the frontend wants to
+/// match on domain errors (validation failures, not-found, permission denied) rather than parse
+/// an opaque string. If the rejection can't be deserialized into `E`, the raw rejection is
+/// returned as [`InvokeError::Other`] instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::tauri::invoke_result;
+/// use tauri_api::error::InvokeError;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize)]
+/// enum LoginError {
+///     NotFound,
+///     WrongPassword,
+/// }
+///
+/// match invoke_result::<_, (), LoginError>("login", &()).await {
+///     Ok(()) => {}
+///     Err(InvokeError::Typed(LoginError::NotFound)) => {}
+///     Err(InvokeError::Typed(LoginError::WrongPassword)) => {}
+///     Err(InvokeError::Other(err)) => {}
+/// }
+/// ```
+///
+/// @param cmd The command name.
+/// @param args The optional arguments to pass to the command.
+/// @return A promise resolving to the backend response, or rejecting with the command's own error type.
+#[inline(always)]
+pub async fn invoke_result<
+    A: Serialize,
+    R: DeserializeOwned,
+    E: DeserializeOwned + std::fmt::Debug,
+>(
+    cmd: &str,
+    args: &A,
+) -> std::result::Result<R, crate::error::InvokeError<E>> {
+    let res = inner::invoke(cmd, serde_wasm_bindgen::to_value(args).unwrap()).await;
+
+    match res {
+        Ok(raw) => Ok(serde_wasm_bindgen::from_value(raw).map_err(crate::Error::Serde)?),
+        Err(raw) => match serde_wasm_bindgen::from_value::<E>(raw.clone()) {
+            Ok(err) => Err(crate::error::InvokeError::Typed(err)),
+            Err(_) => Err(crate::error::InvokeError::Other(crate::Error::Other(raw))),
+        },
+    }
+}
+
 /// Transforms a callback function to a string identifier that can be passed to the backend.
 /// The backend uses the identifier to `eval()` the callback.
 ///
@@ -87,6 +310,126 @@ pub async fn transform_callback<T: DeserializeOwned>(callback: &dyn Fn(T), once:
     .unwrap()
 }
 
+struct ChannelState<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// An open-ended stream of messages pushed by the backend, for commands that report incremental
+/// results (download progress, log tailing, long computations) instead of a single response.
+///
+/// Pass [`Channel::id`] as an argument to [`invoke`]; the backend uses it to push messages which
+/// then surface through the [`Stream`] implementation.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::tauri::{invoke, Channel};
+/// use futures::StreamExt;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize)]
+/// struct Args {
+///     on_progress: f64,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Progress {
+///     percent: f64,
+/// }
+///
+/// let mut channel = Channel::<Progress>::new().await;
+/// invoke::<_, ()>("download", &Args { on_progress: channel.id() }).await.unwrap();
+///
+/// while let Some(progress) = channel.next().await {
+///     println!("{}%", progress.percent);
+/// }
+/// ```
+pub struct Channel<T> {
+    id: f64,
+    state: Rc<RefCell<ChannelState<T>>>,
+    // Kept alive for as long as the channel is, since the backend may call it at any time.
+    _callback: Box<dyn Fn(T)>,
+}
+
+impl<T: DeserializeOwned + 'static> Channel<T> {
+    /// Create a new channel, registering its callback with the backend.
+    pub async fn new() -> Self {
+        let state = Rc::new(RefCell::new(ChannelState {
+            queue: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }));
+
+        let callback_state = state.clone();
+        let callback: Box<dyn Fn(T)> = Box::new(move |message: T| {
+            let mut state = callback_state.borrow_mut();
+            state.queue.push_back(message);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        let id = transform_callback(&*callback, false).await;
+
+        Self {
+            id,
+            state,
+            _callback: callback,
+        }
+    }
+
+    /// The identifier of this channel, to be passed as an argument to [`invoke`] so the backend
+    /// knows where to push messages.
+    pub fn id(&self) -> f64 {
+        self.id
+    }
+
+    /// Stop delivering messages to this channel's [`Stream`] and end it. Safe to call more than
+    /// once.
+    ///
+    /// Note this only affects the local stream: `tauri.js` doesn't expose a way to unregister a
+    /// callback registered with `transformCallback`, so the identifier returned by [`Channel::id`]
+    /// is left registered on the JS side for the lifetime of the page. The backend should stop
+    /// invoking it once it's done (e.g. when the download completes) rather than relying on the
+    /// frontend to tear it down.
+    pub fn close(&self) {
+        let mut state = self.state.borrow_mut();
+        if state.closed {
+            return;
+        }
+
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Stream for Channel<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(message) = state.queue.pop_front() {
+            Poll::Ready(Some(message))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Drop for Channel<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
 mod inner {
     use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
@@ -98,3 +441,137 @@ mod inner {
         pub async fn transformCallback(callback: &dyn Fn(JsValue), once: bool) -> JsValue;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::{RawWaker, RawWakerVTable};
+
+    // `Channel::new` awaits `transform_callback`, which calls into JS and can't run on a host
+    // target, so these tests build `Channel`/`ChannelState` directly instead.
+    fn test_channel<T>() -> Channel<T> {
+        Channel {
+            id: 0.0,
+            state: Rc::new(RefCell::new(ChannelState {
+                queue: VecDeque::new(),
+                waker: None,
+                closed: false,
+            })),
+            _callback: Box::new(|_: T| {}),
+        }
+    }
+
+    fn flagging_waker() -> (Waker, Arc<AtomicBool>) {
+        fn clone(data: *const ()) -> RawWaker {
+            unsafe { Arc::increment_strong_count(data as *const AtomicBool) };
+            RawWaker::new(data, &VTABLE)
+        }
+        fn wake(data: *const ()) {
+            wake_by_ref(data);
+            drop(unsafe { Arc::from_raw(data as *const AtomicBool) });
+        }
+        fn wake_by_ref(data: *const ()) {
+            unsafe { &*(data as *const AtomicBool) }.store(true, Ordering::SeqCst);
+        }
+        fn drop_raw(data: *const ()) {
+            drop(unsafe { Arc::from_raw(data as *const AtomicBool) });
+        }
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_raw);
+
+        let woken = Arc::new(AtomicBool::new(false));
+        let raw = RawWaker::new(Arc::into_raw(woken.clone()) as *const (), &VTABLE);
+        (unsafe { Waker::from_raw(raw) }, woken)
+    }
+
+    #[test]
+    fn poll_returns_message_enqueued_before_poll() {
+        let mut channel = test_channel::<u32>();
+        channel.state.borrow_mut().queue.push_back(42);
+
+        let (waker, _woken) = flagging_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(
+            Pin::new(&mut channel).poll_next(&mut cx),
+            Poll::Ready(Some(42))
+        );
+    }
+
+    #[test]
+    fn poll_wakes_once_message_is_enqueued_after_poll() {
+        let mut channel = test_channel::<u32>();
+        let (waker, woken) = flagging_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(Pin::new(&mut channel).poll_next(&mut cx), Poll::Pending);
+        assert!(!woken.load(Ordering::SeqCst));
+
+        {
+            let mut state = channel.state.borrow_mut();
+            state.queue.push_back(7);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+        assert!(woken.load(Ordering::SeqCst));
+
+        assert_eq!(
+            Pin::new(&mut channel).poll_next(&mut cx),
+            Poll::Ready(Some(7))
+        );
+    }
+
+    #[test]
+    fn close_while_pending_wakes_and_ends_the_stream() {
+        let mut channel = test_channel::<u32>();
+        let (waker, woken) = flagging_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(Pin::new(&mut channel).poll_next(&mut cx), Poll::Pending);
+
+        channel.close();
+        assert!(woken.load(Ordering::SeqCst));
+
+        assert_eq!(Pin::new(&mut channel).poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn close_is_idempotent() {
+        let channel = test_channel::<u32>();
+
+        channel.close();
+        // A second close must not panic, and must not wake a waker that isn't there anymore.
+        channel.close();
+
+        assert!(channel.state.borrow().closed);
+    }
+
+    // `invoke_result` itself can't run on a host target — it goes through `inner::invoke`, which
+    // requires a JS engine — so this only exercises the `InvokeError` shape its match arms
+    // produce: a successfully deserialized rejection takes `Typed`, and match callers can tell it
+    // apart from the `Other` fallback.
+    #[test]
+    fn invoke_error_distinguishes_typed_from_fallback() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        enum LoginError {
+            NotFound,
+        }
+
+        let typed: std::result::Result<(), crate::error::InvokeError<LoginError>> =
+            Err(crate::error::InvokeError::Typed(LoginError::NotFound));
+        assert!(matches!(
+            typed,
+            Err(crate::error::InvokeError::Typed(LoginError::NotFound))
+        ));
+        assert_eq!(format!("{}", typed.unwrap_err()), "NotFound");
+
+        let fallback: std::result::Result<(), crate::error::InvokeError<LoginError>> = Err(
+            crate::error::InvokeError::Other(crate::Error::Other(wasm_bindgen::JsValue::NULL)),
+        );
+        assert!(!matches!(fallback, Err(crate::error::InvokeError::Typed(_))));
+        assert!(matches!(fallback, Err(crate::error::InvokeError::Other(_))));
+    }
+}